@@ -0,0 +1,89 @@
+use std::{collections::HashMap, path::PathBuf};
+
+use serde_json::Value;
+
+use crate::StartDebuggingRequestArgumentsRequest;
+
+/// How a debug session should be started: run a new process (`Launch`) or
+/// attach to one that's already running (`Attach`).
+#[derive(Debug, Clone, PartialEq)]
+pub enum DebugRequest {
+    Launch(LaunchRequest),
+    Attach(AttachRequest),
+}
+
+impl DebugRequest {
+    pub fn to_dap(&self) -> StartDebuggingRequestArgumentsRequest {
+        match self {
+            Self::Launch(_) => StartDebuggingRequestArgumentsRequest::Launch,
+            Self::Attach(_) => StartDebuggingRequestArgumentsRequest::Attach,
+        }
+    }
+}
+
+/// Which terminal (if any) an adapter should route the debuggee's stdio
+/// through.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LaunchRequestConsole {
+    InternalConsole,
+    IntegratedTerminal,
+    ExternalTerminal,
+}
+
+impl LaunchRequestConsole {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::InternalConsole => "internalConsole",
+            Self::IntegratedTerminal => "integratedTerminal",
+            Self::ExternalTerminal => "externalTerminal",
+        }
+    }
+}
+
+/// A handful of web frameworks some adapters special-case so they can set
+/// up debug/reload behavior correctly. Kept here rather than in a specific
+/// adapter crate so any `DebugRequest` producer/consumer can share it
+/// without that adapter crate becoming a dependency of this one.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LaunchRequestPurpose {
+    Django,
+    Flask,
+    Gevent,
+    Pyramid,
+}
+
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct LaunchRequest {
+    pub program: String,
+    pub cwd: Option<PathBuf>,
+    pub args: Vec<String>,
+    pub env: HashMap<String, String>,
+    /// Run `python -m <module>` (or the equivalent for other languages)
+    /// instead of a script path.
+    pub module: Option<String>,
+    /// Run an inline snippet of source instead of a script path.
+    pub code: Option<String>,
+    /// Which terminal the adapter should route stdio through.
+    pub console: Option<LaunchRequestConsole>,
+    /// Whether the debugger should stay in user-written code and skip
+    /// stepping into library code.
+    pub just_my_code: Option<bool>,
+    /// Framework-specific launch behavior an adapter may special-case.
+    pub purpose: Option<LaunchRequestPurpose>,
+}
+
+impl LaunchRequest {
+    pub fn env_json(&self) -> Value {
+        Value::Object(
+            self.env
+                .iter()
+                .map(|(key, value)| (key.clone(), Value::String(value.clone())))
+                .collect(),
+        )
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct AttachRequest {
+    pub process_id: u32,
+}
@@ -0,0 +1,22 @@
+mod debug_format;
+
+use serde_json::Value;
+
+pub use debug_format::{
+    AttachRequest, DebugRequest, LaunchRequest, LaunchRequestConsole, LaunchRequestPurpose,
+};
+
+/// Arguments sent back to the DAP client to actually start the session,
+/// once an adapter has resolved its launch/attach configuration into the
+/// shape its debug server expects.
+#[derive(Debug, Clone)]
+pub struct StartDebuggingRequestArguments {
+    pub configuration: Value,
+    pub request: StartDebuggingRequestArgumentsRequest,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StartDebuggingRequestArgumentsRequest {
+    Launch,
+    Attach,
+}
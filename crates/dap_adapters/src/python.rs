@@ -1,10 +1,233 @@
 use crate::*;
-use dap::{DebugRequest, StartDebuggingRequestArguments, adapters::DebugTaskDefinition};
+use dap::{
+    adapters::DebugTaskDefinition, DebugRequest, LaunchRequestPurpose,
+    StartDebuggingRequestArguments,
+};
 use gpui::{AsyncApp, SharedString};
 use language::LanguageName;
-use std::{collections::HashMap, ffi::OsStr, path::PathBuf, sync::OnceLock};
+use serde::{Deserialize, Serialize};
+use std::{
+    collections::HashMap,
+    ffi::OsStr,
+    path::{Path, PathBuf},
+    sync::OnceLock,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
 use util::ResultExt;
 
+/// Name of the on-disk freshness cache for the GitHub "latest version"
+/// check, stored alongside the installed debugpy versions for this adapter.
+const LATEST_VERSION_CACHE_FILE_NAME: &str = "latest-version.json";
+
+/// How long a cached "latest version" check is trusted before we hit
+/// GitHub again, mirroring cargo's fingerprint freshness window.
+const LATEST_VERSION_CACHE_TTL: Duration = Duration::from_secs(24 * 60 * 60);
+
+/// What we persist about the last successful `fetch_latest_adapter_version`
+/// call, so subsequent session starts can skip the network round-trip.
+#[derive(Debug, Serialize, Deserialize)]
+struct CachedLatestVersion {
+    tag_name: String,
+    url: String,
+    fetched_at_unix_secs: u64,
+}
+
+impl CachedLatestVersion {
+    fn from_adapter_version(version: &AdapterVersion) -> Self {
+        Self {
+            tag_name: version.tag_name.clone(),
+            url: version.url.clone(),
+            fetched_at_unix_secs: SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .unwrap_or_default()
+                .as_secs(),
+        }
+    }
+
+    fn is_fresh(&self) -> bool {
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_secs();
+        now.saturating_sub(self.fetched_at_unix_secs) < LATEST_VERSION_CACHE_TTL.as_secs()
+    }
+}
+
+/// Names of the files we consult to pin a project to a specific interpreter,
+/// checked in this order in each directory as we walk up from the debug
+/// target towards the filesystem root.
+const PYTHON_VERSION_FILE_NAMES: [&str; 2] = [".python-version", ".python-versions"];
+
+/// A single non-comment, non-blank line from a `.python-version(s)` file.
+#[derive(Debug, PartialEq, Eq)]
+enum PythonVersionPin {
+    /// A literal path to an interpreter, e.g. `/usr/bin/python3.11`.
+    Path(PathBuf),
+    /// A bare or implementation-qualified version request, e.g. `3.11`,
+    /// `3.11.4`, or `cpython@3.11`.
+    Version {
+        implementation: Option<String>,
+        version: String,
+    },
+}
+
+impl PythonVersionPin {
+    fn parse(line: &str) -> Option<Self> {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            return None;
+        }
+
+        if let Some((implementation, version)) = line.split_once('@') {
+            return Some(Self::Version {
+                implementation: Some(implementation.to_string()),
+                version: version.to_string(),
+            });
+        }
+
+        if line.contains('/') || line.contains('\\') {
+            return Some(Self::Path(PathBuf::from(line)));
+        }
+
+        Some(Self::Version {
+            implementation: None,
+            version: line.to_string(),
+        })
+    }
+
+    /// Finds the best match for this pin among the interpreters the
+    /// toolchain store reports as installed, preferring an exact match over
+    /// the newest version that is merely prefix-compatible. A `Path` pin is
+    /// only trusted once we've confirmed the interpreter actually exists, so
+    /// a stale path surfaces a clear "not found" error instead of silently
+    /// resolving to a dead command.
+    async fn resolve(&self, installed: &[language::Toolchain]) -> Option<String> {
+        match self {
+            Self::Path(path) => smol::fs::metadata(path)
+                .await
+                .ok()
+                .map(|_| path.to_string_lossy().into_owned()),
+            Self::Version { version, .. } => {
+                let requested = Self::version_components(version)?;
+                let mut best: Option<(&language::Toolchain, Vec<u64>)> = None;
+
+                for toolchain in installed {
+                    let Some(candidate) = Self::version_components(toolchain.name.as_ref()) else {
+                        continue;
+                    };
+                    if candidate == requested {
+                        return Some(toolchain.path.to_string());
+                    }
+                    let is_better = match &best {
+                        Some((_, best_version)) => candidate > *best_version,
+                        None => true,
+                    };
+                    if Self::is_prefix_compatible(&requested, &candidate) && is_better {
+                        best = Some((toolchain, candidate));
+                    }
+                }
+
+                best.map(|(toolchain, _)| toolchain.path.to_string())
+            }
+        }
+    }
+
+    /// Parses a dotted version string (ignoring any leading non-digit text,
+    /// e.g. `"Python 3.11.4"`) into its numeric components.
+    fn version_components(text: &str) -> Option<Vec<u64>> {
+        let digits_and_dots: String = text
+            .chars()
+            .skip_while(|c| !c.is_ascii_digit())
+            .take_while(|c| c.is_ascii_digit() || *c == '.')
+            .collect();
+
+        if digits_and_dots.is_empty() {
+            return None;
+        }
+
+        digits_and_dots
+            .split('.')
+            .map(|part| part.parse::<u64>().ok())
+            .collect()
+    }
+
+    /// Whether `candidate` is `requested` with zero or more trailing
+    /// components appended, e.g. `requested = [3, 11]` is compatible with
+    /// `candidate = [3, 11, 4]` but not `[3, 110]` or `[3, 1]`.
+    fn is_prefix_compatible(requested: &[u64], candidate: &[u64]) -> bool {
+        requested.len() <= candidate.len()
+            && requested
+                .iter()
+                .zip(candidate)
+                .all(|(requested, candidate)| requested == candidate)
+    }
+}
+
+/// Outcome of looking up a `.python-version`/`.python-versions` pin.
+enum PinnedInterpreter {
+    /// No pin file was found; callers should fall back to scanning `PATH`.
+    NotPinned,
+    /// The pin resolved to an installed interpreter.
+    Found(String),
+    /// A bare version was pinned but no installed interpreter satisfies it;
+    /// callers can try a managed download of this version instead.
+    RequestedVersion(String),
+}
+
+/// debugpy's boolean key for a given [`LaunchRequestPurpose`]. The purpose
+/// itself lives in the `dap` crate (shared across adapters); only the
+/// debugpy-specific JSON key name belongs here.
+fn debugpy_purpose_key(purpose: LaunchRequestPurpose) -> &'static str {
+    match purpose {
+        LaunchRequestPurpose::Django => "django",
+        LaunchRequestPurpose::Flask => "flask",
+        LaunchRequestPurpose::Gevent => "gevent",
+        LaunchRequestPurpose::Pyramid => "pyramid",
+    }
+}
+
+/// The directory names we check, in order, when looking for a project-local
+/// virtual environment to activate for the debug session.
+const VIRTUAL_ENV_DIR_NAMES: [&str; 2] = [".venv", "venv"];
+
+/// A project-local virtual environment, detected by the presence of a
+/// `pyvenv.cfg` marker file in one of [`VIRTUAL_ENV_DIR_NAMES`].
+struct VirtualEnv {
+    root: PathBuf,
+    /// The `home` entry from `pyvenv.cfg`: the directory containing the base
+    /// interpreter this venv was created from. This is what `PYTHONHOME`
+    /// needs to be set to (matching what `activate` scripts effectively
+    /// preserve) so an inherited, unrelated `PYTHONHOME` can't override
+    /// `pyvenv.cfg`'s own prefix resolution.
+    home: Option<String>,
+}
+
+/// Parses the `home = ...` entry out of a `pyvenv.cfg` file's contents.
+fn parse_pyvenv_cfg_home(contents: &str) -> Option<String> {
+    contents.lines().find_map(|line| {
+        let (key, value) = line.split_once('=')?;
+        (key.trim() == "home").then(|| value.trim().to_string())
+    })
+}
+
+impl VirtualEnv {
+    fn python_bin(&self) -> PathBuf {
+        if cfg!(windows) {
+            self.root.join("Scripts").join("python.exe")
+        } else {
+            self.root.join("bin").join("python")
+        }
+    }
+
+    fn bin_dir(&self) -> PathBuf {
+        if cfg!(windows) {
+            self.root.join("Scripts")
+        } else {
+            self.root.join("bin")
+        }
+    }
+}
+
 #[derive(Default)]
 pub(crate) struct PythonDebugAdapter {
     checked: OnceLock<()>,
@@ -31,7 +254,13 @@ impl PythonDebugAdapter {
                 map.insert("processId".into(), attach.process_id.into());
             }
             DebugRequest::Launch(launch) => {
-                map.insert("program".into(), launch.program.clone().into());
+                if let Some(module) = launch.module.as_ref().filter(|module| !module.is_empty()) {
+                    map.insert("module".into(), module.clone().into());
+                } else if let Some(code) = launch.code.as_ref().filter(|code| !code.is_empty()) {
+                    map.insert("code".into(), code.clone().into());
+                } else {
+                    map.insert("program".into(), launch.program.clone().into());
+                }
                 map.insert("args".into(), launch.args.clone().into());
                 if !launch.env.is_empty() {
                     map.insert("env".into(), launch.env_json());
@@ -43,6 +272,15 @@ impl PythonDebugAdapter {
                 if let Some(cwd) = launch.cwd.as_ref() {
                     map.insert("cwd".into(), cwd.to_string_lossy().into_owned().into());
                 }
+                if let Some(purpose) = launch.purpose {
+                    map.insert(debugpy_purpose_key(purpose).into(), true.into());
+                }
+                if let Some(console) = launch.console.as_ref() {
+                    map.insert("console".into(), console.as_str().into());
+                }
+                if let Some(just_my_code) = launch.just_my_code {
+                    map.insert("justMyCode".into(), just_my_code.into());
+                }
             }
         }
         StartDebuggingRequestArguments {
@@ -91,6 +329,253 @@ impl PythonDebugAdapter {
         Ok(())
     }
 
+    fn latest_version_cache_path(adapter_path: &Path) -> PathBuf {
+        adapter_path.join(LATEST_VERSION_CACHE_FILE_NAME)
+    }
+
+    async fn cached_latest_version(adapter_path: &Path) -> Option<CachedLatestVersion> {
+        let contents = smol::fs::read_to_string(&Self::latest_version_cache_path(adapter_path))
+            .await
+            .ok()?;
+        serde_json::from_str(&contents).log_err()
+    }
+
+    async fn write_latest_version_cache(
+        adapter_path: &Path,
+        cache: &CachedLatestVersion,
+    ) -> Result<()> {
+        smol::fs::create_dir_all(adapter_path).await?;
+        smol::fs::write(
+            &Self::latest_version_cache_path(adapter_path),
+            serde_json::to_string(cache)?,
+        )
+        .await?;
+        Ok(())
+    }
+
+    /// Whether the version directory the cache points at is still present
+    /// on disk, i.e. whether we can skip installing entirely.
+    async fn installed_version_is_present(adapter_path: &Path, tag_name: &str) -> bool {
+        let version_dir_name = format!("{}_{}", Self::ADAPTER_NAME, tag_name);
+        util::fs::find_file_name_in_dir(adapter_path, |file_name| file_name == version_dir_name)
+            .await
+            .is_some()
+    }
+
+    /// A `python-build-standalone` release used to provide a managed
+    /// interpreter when no system Python can be found. Pinned to a known
+    /// release tag so installs are reproducible across sessions.
+    const MANAGED_PYTHON_RELEASE_TAG: &'static str = "20240814";
+    const DEFAULT_MANAGED_PYTHON_VERSION: &'static str = "3.12.5";
+
+    fn managed_python_host_triple() -> Result<&'static str> {
+        match (std::env::consts::OS, std::env::consts::ARCH) {
+            ("linux", "x86_64") => Ok("x86_64-unknown-linux-gnu"),
+            ("linux", "aarch64") => Ok("aarch64-unknown-linux-gnu"),
+            ("macos", "x86_64") => Ok("x86_64-apple-darwin"),
+            ("macos", "aarch64") => Ok("aarch64-apple-darwin"),
+            ("windows", "x86_64") => Ok("x86_64-pc-windows-msvc"),
+            (os, arch) => Err(anyhow!("no managed Python build available for {os}/{arch}")),
+        }
+    }
+
+    fn managed_python_bin_path(install_dir: &Path) -> PathBuf {
+        if cfg!(windows) {
+            install_dir.join("python").join("python.exe")
+        } else {
+            install_dir.join("python").join("bin").join("python3")
+        }
+    }
+
+    /// Downloads a standalone CPython build for the host platform, keyed by
+    /// requested version + platform so repeated sessions reuse the same
+    /// extracted toolchain. Used as a last resort when no system or pinned
+    /// interpreter is available.
+    async fn managed_interpreter(
+        &self,
+        delegate: &Arc<dyn DapDelegate>,
+        requested_version: Option<&str>,
+    ) -> Result<String> {
+        let version = requested_version.unwrap_or(Self::DEFAULT_MANAGED_PYTHON_VERSION);
+        // python-build-standalone only publishes assets for full `X.Y.Z`
+        // releases; a bare `X.Y` pin (as `.python-version` commonly has)
+        // would silently 404 if passed straight through.
+        if !matches!(
+            PythonVersionPin::version_components(version).as_deref(),
+            Some([_, _, _])
+        ) {
+            return Err(anyhow!(
+                "cannot download a managed Python for \"{version}\": a full major.minor.patch version (e.g. \"3.11.9\") is required"
+            ));
+        }
+        let triple = Self::managed_python_host_triple()?;
+        let tag_name = format!("{version}-{triple}");
+
+        let managed_name = DebugAdapterName(SharedString::new_static("Python-Managed"));
+        // `download_adapter_from_github` extracts into
+        // `<adapter_path>/<name>_<tag_name>`, matching the `{name}_` prefix
+        // `installed_version_is_present`/`get_installed_binary` look for.
+        let install_dir = paths::debug_adapters_dir()
+            .join(managed_name.as_ref())
+            .join(format!("{}_{}", managed_name.as_ref(), tag_name));
+        let python_bin = Self::managed_python_bin_path(&install_dir);
+
+        if smol::fs::metadata(&python_bin).await.is_ok() {
+            return Ok(python_bin.to_string_lossy().into_owned());
+        }
+
+        delegate.output_to_console(format!(
+            "No Python interpreter found; downloading managed CPython {version} ({triple})..."
+        ));
+
+        let asset_name = format!(
+            "cpython-{version}+{tag}-{triple}-install_only.tar.gz",
+            tag = Self::MANAGED_PYTHON_RELEASE_TAG
+        );
+        let url = format!(
+            "https://github.com/astral-sh/python-build-standalone/releases/download/{tag}/{asset_name}",
+            tag = Self::MANAGED_PYTHON_RELEASE_TAG
+        );
+
+        adapters::download_adapter_from_github(
+            managed_name,
+            AdapterVersion { tag_name, url },
+            adapters::DownloadedFileType::GzipTar,
+            delegate.as_ref(),
+        )
+        .await?;
+
+        if smol::fs::metadata(&python_bin).await.is_err() {
+            return Err(anyhow!(
+                "downloaded managed Python {version} but did not find an interpreter at {}",
+                python_bin.display()
+            ));
+        }
+
+        Ok(python_bin.to_string_lossy().into_owned())
+    }
+
+    /// Honors an already-activated `VIRTUAL_ENV`, then falls back to
+    /// scanning the worktree root for a `.venv`/`venv` directory that looks
+    /// like a virtual environment. A candidate is only trusted once we've
+    /// confirmed both its `pyvenv.cfg` marker and its interpreter binary are
+    /// actually there, so a stale or empty `VIRTUAL_ENV`, or a venv that's
+    /// missing its binary, doesn't blot out an otherwise-discoverable
+    /// interpreter.
+    async fn detect_virtual_env(
+        delegate: &Arc<dyn DapDelegate>,
+        worktree_root: &Path,
+    ) -> Option<VirtualEnv> {
+        let active = delegate
+            .shell_env()
+            .await
+            .get("VIRTUAL_ENV")
+            .filter(|path| !path.is_empty())
+            .map(|path| PathBuf::from(path.as_str()));
+
+        let candidates = active.into_iter().chain(
+            VIRTUAL_ENV_DIR_NAMES
+                .iter()
+                .map(|dir_name| worktree_root.join(dir_name)),
+        );
+
+        for root in candidates {
+            let Ok(pyvenv_cfg) = delegate.read_text_file(root.join("pyvenv.cfg")).await else {
+                continue;
+            };
+            let virtual_env = VirtualEnv {
+                home: parse_pyvenv_cfg_home(&pyvenv_cfg),
+                root,
+            };
+            if smol::fs::metadata(virtual_env.python_bin()).await.is_ok() {
+                return Some(virtual_env);
+            }
+        }
+
+        None
+    }
+
+    /// Looks for a `.python-version`/`.python-versions` pin above the debug
+    /// target and, if one is found, resolves it against the interpreters the
+    /// toolchain store knows about.
+    async fn pinned_interpreter(
+        &self,
+        delegate: &Arc<dyn DapDelegate>,
+        config: &DebugTaskDefinition,
+        cx: &mut AsyncApp,
+    ) -> Result<PinnedInterpreter> {
+        let start_dir = match &config.request {
+            DebugRequest::Launch(launch) => launch.cwd.clone(),
+            DebugRequest::Attach(_) => None,
+        }
+        .unwrap_or_else(|| delegate.worktree_root_path().to_path_buf());
+
+        let Some(pins) = Self::read_version_pins(delegate, &start_dir).await else {
+            return Ok(PinnedInterpreter::NotPinned);
+        };
+
+        let installed = delegate
+            .toolchain_store()
+            .list_toolchains(
+                delegate.worktree_id(),
+                Arc::from("".as_ref()),
+                language::LanguageName::new(Self::LANGUAGE_NAME),
+                cx,
+            )
+            .await
+            .unwrap_or_default();
+
+        for pin in &pins {
+            if let Some(path) = pin.resolve(&installed).await {
+                return Ok(PinnedInterpreter::Found(path));
+            }
+        }
+
+        // None of the pins matched an installed toolchain. A bare version
+        // pin (as opposed to an explicit path) can still be satisfied by
+        // downloading a managed interpreter for that version, so surface it
+        // to the caller instead of failing outright.
+        if let Some(PythonVersionPin::Version { version, .. }) = pins
+            .iter()
+            .find(|pin| matches!(pin, PythonVersionPin::Version { .. }))
+        {
+            return Ok(PinnedInterpreter::RequestedVersion(version.clone()));
+        }
+
+        Err(anyhow!(
+            "none of the interpreters pinned in `.python-version`/`.python-versions` could be found"
+        ))
+    }
+
+    /// Walks up from `start_dir` towards the filesystem root, returning the
+    /// parsed pins of the first `.python-version` or `.python-versions` file
+    /// that holds at least one; files with only blank or comment lines are
+    /// skipped.
+    async fn read_version_pins(
+        delegate: &Arc<dyn DapDelegate>,
+        start_dir: &Path,
+    ) -> Option<Vec<PythonVersionPin>> {
+        let mut dir = start_dir.to_path_buf();
+        loop {
+            for file_name in PYTHON_VERSION_FILE_NAMES {
+                if let Some(contents) = delegate.read_text_file(dir.join(file_name)).await.log_err()
+                {
+                    let pins: Vec<_> = contents
+                        .lines()
+                        .filter_map(PythonVersionPin::parse)
+                        .collect();
+                    if !pins.is_empty() {
+                        return Some(pins);
+                    }
+                }
+            }
+
+            if !dir.pop() {
+                return None;
+            }
+        }
+    }
+
     async fn get_installed_binary(
         &self,
         delegate: &Arc<dyn DapDelegate>,
@@ -115,6 +600,8 @@ impl PythonDebugAdapter {
             .ok_or_else(|| anyhow!("Debugpy directory not found"))?
         };
 
+        let virtual_env = Self::detect_virtual_env(delegate, delegate.worktree_root_path()).await;
+
         let toolchain = delegate
             .toolchain_store()
             .active_toolchain(
@@ -125,25 +612,92 @@ impl PythonDebugAdapter {
             )
             .await;
 
+        let mut requested_version = None;
+        let mut used_virtual_env = false;
         let python_path = if let Some(toolchain) = toolchain {
+            // An explicitly selected toolchain (e.g. via Zed's toolchain
+            // picker) always wins over an auto-detected virtualenv.
             Some(toolchain.path.to_string())
+        } else if let Some(virtual_env) = virtual_env.as_ref() {
+            used_virtual_env = true;
+            Some(virtual_env.python_bin().to_string_lossy().into_owned())
         } else {
-            let mut name = None;
-
-            for cmd in BINARY_NAMES {
-                name = delegate
-                    .which(OsStr::new(cmd))
-                    .await
-                    .map(|path| path.to_string_lossy().to_string());
-                if name.is_some() {
-                    break;
+            match self.pinned_interpreter(delegate, config, cx).await? {
+                PinnedInterpreter::Found(path) => Some(path),
+                PinnedInterpreter::RequestedVersion(version) => {
+                    requested_version = Some(version);
+                    None
+                }
+                PinnedInterpreter::NotPinned => {
+                    let mut name = None;
+
+                    for cmd in BINARY_NAMES {
+                        name = delegate
+                            .which(OsStr::new(cmd))
+                            .await
+                            .map(|path| path.to_string_lossy().to_string());
+                        if name.is_some() {
+                            break;
+                        }
+                    }
+                    name
                 }
             }
-            name
+        };
+
+        let python_path = match python_path {
+            Some(path) => path,
+            None => {
+                self.managed_interpreter(delegate, requested_version.as_deref())
+                    .await?
+            }
+        };
+
+        let mut envs = HashMap::default();
+        // Only activate the venv's environment when we actually chose to run
+        // its interpreter; an explicitly selected toolchain wins over the
+        // venv for `python_path` above, and wrapping that toolchain's
+        // process in another venv's `VIRTUAL_ENV`/`PATH`/`PYTHONHOME` would
+        // make it resolve packages from the wrong environment.
+        if used_virtual_env {
+            let virtual_env = virtual_env.as_ref().expect("used_virtual_env implies Some");
+            envs.insert(
+                "VIRTUAL_ENV".to_string(),
+                virtual_env.root.to_string_lossy().into_owned(),
+            );
+            let path_separator = if cfg!(windows) { ';' } else { ':' };
+            let existing_path = delegate
+                .shell_env()
+                .await
+                .get("PATH")
+                .cloned()
+                .unwrap_or_default();
+            envs.insert(
+                "PATH".to_string(),
+                format!(
+                    "{}{path_separator}{existing_path}",
+                    virtual_env.bin_dir().to_string_lossy()
+                ),
+            );
+            // A set `PYTHONHOME` overrides `pyvenv.cfg`'s own prefix
+            // resolution (that's precisely why `activate` unsets it), so an
+            // inherited, unrelated `PYTHONHOME` would break the venv
+            // interpreter. We can't unset an inherited variable through
+            // `envs`, so set it to the venv's own base-interpreter home
+            // (pyvenv.cfg's `home` entry) instead, matching what resolution
+            // would land on if `PYTHONHOME` were unset.
+            if let Some(home) = virtual_env.home.as_ref() {
+                envs.insert("PYTHONHOME".to_string(), home.clone());
+            }
+        }
+
+        let cwd = match &config.request {
+            DebugRequest::Launch(launch) => launch.cwd.clone(),
+            DebugRequest::Attach(_) => None,
         };
 
         Ok(DebugAdapterBinary {
-            command: python_path.ok_or(anyhow!("failed to find binary path for python"))?,
+            command: python_path,
             arguments: vec![
                 debugpy_dir
                     .join(Self::ADAPTER_PATH)
@@ -157,8 +711,8 @@ impl PythonDebugAdapter {
                 port,
                 timeout,
             }),
-            cwd: None,
-            envs: HashMap::default(),
+            cwd,
+            envs,
             request_args: self.request_args(config),
         })
     }
@@ -182,9 +736,48 @@ impl DebugAdapter for PythonDebugAdapter {
         cx: &mut AsyncApp,
     ) -> Result<DebugAdapterBinary> {
         if self.checked.set(()).is_ok() {
-            delegate.output_to_console(format!("Checking latest version of {}...", self.name()));
-            if let Some(version) = self.fetch_latest_adapter_version(delegate).await.log_err() {
-                self.install_binary(version, delegate).await?;
+            let adapter_path = paths::debug_adapters_dir().join(self.name().as_ref());
+            let cached_version = Self::cached_latest_version(&adapter_path).await;
+            let fresh_cache = cached_version.filter(CachedLatestVersion::is_fresh);
+            let is_clean = match &fresh_cache {
+                Some(cached) => {
+                    Self::installed_version_is_present(&adapter_path, &cached.tag_name).await
+                }
+                None => false,
+            };
+
+            if !is_clean {
+                let cached_fetched_at = fresh_cache
+                    .as_ref()
+                    .map(|cached| cached.fetched_at_unix_secs);
+                let version = if let Some(cached) = fresh_cache {
+                    // The cache is still fresh but the install went missing
+                    // (e.g. the user cleared it); reinstall the same
+                    // version instead of hitting GitHub again.
+                    Some(AdapterVersion {
+                        tag_name: cached.tag_name,
+                        url: cached.url,
+                    })
+                } else {
+                    delegate.output_to_console(format!(
+                        "Checking latest version of {}...",
+                        self.name()
+                    ));
+                    self.fetch_latest_adapter_version(delegate).await.log_err()
+                };
+
+                if let Some(version) = version {
+                    let mut cache_entry = CachedLatestVersion::from_adapter_version(&version);
+                    if let Some(fetched_at) = cached_fetched_at {
+                        // Reinstalled from the cache without fetching: keep
+                        // the time of the fetch that produced this entry.
+                        cache_entry.fetched_at_unix_secs = fetched_at;
+                    }
+                    self.install_binary(version, delegate).await?;
+                    Self::write_latest_version_cache(&adapter_path, &cache_entry)
+                        .await
+                        .log_err();
+                }
             }
         }
 
@@ -192,3 +785,71 @@ impl DebugAdapter for PythonDebugAdapter {
             .await
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_bare_version() {
+        assert_eq!(
+            PythonVersionPin::parse("3.11"),
+            Some(PythonVersionPin::Version {
+                implementation: None,
+                version: "3.11".into(),
+            })
+        );
+    }
+
+    #[test]
+    fn parses_implementation_qualified_version() {
+        assert_eq!(
+            PythonVersionPin::parse("cpython@3.11"),
+            Some(PythonVersionPin::Version {
+                implementation: Some("cpython".into()),
+                version: "3.11".into(),
+            })
+        );
+    }
+
+    #[test]
+    fn parses_path() {
+        assert_eq!(
+            PythonVersionPin::parse("/usr/bin/python3.11"),
+            Some(PythonVersionPin::Path("/usr/bin/python3.11".into()))
+        );
+    }
+
+    #[test]
+    fn ignores_blank_and_comment_lines() {
+        assert_eq!(PythonVersionPin::parse(""), None);
+        assert_eq!(PythonVersionPin::parse("   "), None);
+        assert_eq!(PythonVersionPin::parse("# use 3.11 here"), None);
+    }
+
+    #[test]
+    fn version_components_ignores_leading_text() {
+        assert_eq!(
+            PythonVersionPin::version_components("Python 3.11.4"),
+            Some(vec![3, 11, 4])
+        );
+        assert_eq!(PythonVersionPin::version_components("no digits"), None);
+    }
+
+    #[test]
+    fn prefix_compatible_requires_matching_components_not_just_string_prefix() {
+        assert!(PythonVersionPin::is_prefix_compatible(
+            &[3, 11],
+            &[3, 11, 4]
+        ));
+        assert!(!PythonVersionPin::is_prefix_compatible(
+            &[3, 1],
+            &[3, 11, 4]
+        ));
+        assert!(!PythonVersionPin::is_prefix_compatible(&[3, 2], &[3, 20]));
+        assert!(!PythonVersionPin::is_prefix_compatible(
+            &[3, 11, 4],
+            &[3, 11]
+        ));
+    }
+}